@@ -1,7 +1,7 @@
 use std::{collections::VecDeque, iter::FromIterator};
 
 use crate::{
-    changelog::{section, section::Segment, Section, Version},
+    changelog::{section, section::Segment, DatePrecision, Section, Version},
     ChangeLog,
 };
 
@@ -78,11 +78,13 @@ impl Section {
             (
                 Section::Release {
                     date: dest_date,
+                    date_precision: dest_date_precision,
                     segments: dest_segments,
                     ..
                 },
                 Section::Release {
                     date: src_date,
+                    date_precision: src_date_precision,
                     segments: src_segments,
                     unknown: src_unknown,
                     ..
@@ -105,8 +107,20 @@ impl Section {
                         }
                         Segment::Details(section::Data::Parsed)
                         | Segment::Statistics(section::Data::Parsed)
-                        | Segment::Clippy(section::Data::Parsed) => {
-                            unreachable!("BUG: Clippy, statistics, and details are set if generated, or not present")
+                        | Segment::Clippy(section::Data::Parsed)
+                        | Segment::BreakingChanges(section::Data::Parsed) => {
+                            unreachable!("BUG: Clippy, statistics, breaking changes and details are set if generated, or not present")
+                        }
+                        breaking @ Segment::BreakingChanges(_) => {
+                            // Hand-written breaking changes would otherwise be listed twice.
+                            if !dest_segments.iter().any(is_hand_written_breaking_changes) {
+                                merge_read_only_segment(
+                                    dest_segments,
+                                    |s| matches!(s, Segment::BreakingChanges(_)),
+                                    breaking,
+                                    mode,
+                                )
+                            }
                         }
                         clippy @ Segment::Clippy(_) => merge_read_only_segment(
                             dest_segments,
@@ -128,6 +142,10 @@ impl Section {
                         ),
                     }
                 }
+                // Dates follow the generated date, and keep the time of day if either of them has one.
+                if dest_date.is_none() || src_date_precision == DatePrecision::Time {
+                    *dest_date_precision = src_date_precision;
+                }
                 *dest_date = src_date;
             }
         }
@@ -156,6 +174,19 @@ fn merge_read_only_segment(
     }
 }
 
+fn is_hand_written_breaking_changes(segment: &Segment) -> bool {
+    match segment {
+        Segment::User { markdown } => markdown.lines().any(|line| {
+            line.starts_with('#')
+                && line
+                    .trim_start_matches('#')
+                    .trim_start()
+                    .starts_with(section::segment::BreakingChanges::TITLE)
+        }),
+        _ => false,
+    }
+}
+
 enum Insertion {
     MergeWith(usize),
     At(usize),
@@ -27,6 +27,8 @@ pub enum Section {
     Release {
         name: Version,
         date: Option<time::OffsetDateTime>,
+        /// How much of `date` was parsed or is supposed to be written.
+        date_precision: DatePrecision,
         /// the amount of # in front of the heading denoting the release name
         heading_level: usize,
         /// What came right before the version
@@ -40,6 +42,19 @@ pub enum Section {
     },
 }
 
+/// The precision of a release date, which is retained to write dates back the way they were parsed.
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Default)]
+pub enum DatePrecision {
+    /// Only the day is written, as in `(2021-08-31)`, which is the day of the date in UTC.
+    /// Parsed dates of this precision are at midnight UTC.
+    #[default]
+    Day,
+    /// The time of day and UTC offset are known as well, as in `(2021-08-31T14:05:00+02:00)`.
+    ///
+    /// Such dates keep their precision when they are updated with the date of the tag while merging generated sections.
+    Time,
+}
+
 #[derive(PartialEq, Eq, Debug, Clone)]
 pub enum Version {
     Unreleased,
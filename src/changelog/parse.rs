@@ -8,7 +8,7 @@ use std::{
 use git_repository::bstr::ByteSlice;
 use nom::{
     branch::alt,
-    bytes::complete::{tag, tag_no_case, take_till, take_while, take_while_m_n},
+    bytes::complete::{tag, tag_no_case, take_till, take_while, take_while1, take_while_m_n},
     combinator::{all_consuming, map, map_res, opt},
     error::{FromExternalError, ParseError},
     sequence::{delimited, preceded, separated_pair, terminated, tuple},
@@ -107,6 +107,7 @@ impl Section {
             version_prefix,
             version,
             date,
+            date_precision,
         }: Headline,
         body: String,
     ) -> Self {
@@ -132,6 +133,8 @@ impl Section {
                     record_unknown_range(&mut segments, unknown_range.take(), &body);
                     enum State {
                         SkipGenerated,
+                        /// Headings that users may also write by hand are only generated if marked read-only.
+                        SkipGeneratedIfReadOnly,
                         ConsiderUserAuthored,
                     }
                     let state = match events.next() {
@@ -147,6 +150,13 @@ impl Section {
                             segments.push(Segment::Statistics(section::Data::Parsed));
                             State::SkipGenerated
                         }
+                        Some((Event::Text(title), next_range))
+                            if title.starts_with(section::segment::BreakingChanges::TITLE) =>
+                        {
+                            update_unknown_range(&mut unknown_range, range);
+                            update_unknown_range(&mut unknown_range, next_range);
+                            State::SkipGeneratedIfReadOnly
+                        }
                         Some((Event::Text(title), _range))
                             if title.starts_with(section::segment::Details::TITLE) =>
                         {
@@ -164,7 +174,7 @@ impl Section {
                     events
                         .by_ref()
                         .take_while(|(e, range)| {
-                            if matches!(state, State::ConsiderUserAuthored) {
+                            if !matches!(state, State::SkipGenerated) {
                                 update_unknown_range(&mut unknown_range, range.clone());
                             }
                             !matches!(e, Event::End(Tag::Heading(_, _, _)))
@@ -172,7 +182,24 @@ impl Section {
                         .count();
                     match state {
                         State::SkipGenerated => {
-                            skip_to_next_section_title(&mut events, indent);
+                            skip_to_next_section_title(&mut events, indent, &mut segments, &body);
+                        }
+                        State::SkipGeneratedIfReadOnly => {
+                            let comment_ranges: Vec<_> =
+                                std::iter::from_fn(|| take_html_comment(&mut events)).collect();
+                            if matches!(events.peek(), Some((Event::Html(text), _range)) if text.starts_with(Section::READONLY_TAG.trim_end()))
+                            {
+                                unknown_range = None;
+                                segments.push(Segment::BreakingChanges(section::Data::Parsed));
+                                for comment_range in comment_ranges {
+                                    record_unknown_range(&mut segments, Some(comment_range), &body);
+                                }
+                                skip_to_next_section_title(&mut events, indent, &mut segments, &body);
+                            } else {
+                                for comment_range in comment_ranges {
+                                    update_unknown_range(&mut unknown_range, comment_range);
+                                }
+                            }
                         }
                         State::ConsiderUserAuthored => {}
                     }
@@ -188,6 +215,7 @@ impl Section {
             },
             version_prefix,
             date,
+            date_precision,
             removed_messages,
             heading_level: level,
             segments,
@@ -234,23 +262,65 @@ fn track_unknown_event(unknown_event: Event<'_>, unknown: &mut String) {
     }
 }
 
-fn skip_to_next_section_title(events: &mut Peekable<OffsetIter<'_, '_>>, level: HeadingLevel) {
+/// Skip all generated content, but keep HTML comment blocks placed in between by users as they may carry meaning to them.
+/// Comments within generated blocks, like list items, are regenerated along with them.
+fn skip_to_next_section_title(
+    events: &mut Peekable<OffsetIter<'_, '_>>,
+    level: HeadingLevel,
+    segments: &mut Vec<section::Segment>,
+    markdown: &str,
+) {
+    let mut depth = 0usize;
     while let Some((event, _range)) = events.peek() {
         match event {
-            Event::Start(Tag::Heading(indent, _, _)) if *indent == level => break,
+            Event::Start(Tag::Heading(indent, _, _)) if *indent == level && depth == 0 => break,
+            Event::Html(text) if depth == 0 && is_html_comment(text) => {
+                let comment_range = take_html_comment(events);
+                record_unknown_range(segments, comment_range, markdown);
+            }
+            Event::Start(_) => {
+                depth += 1;
+                events.next();
+            }
+            Event::End(_) => {
+                depth = depth.saturating_sub(1);
+                events.next();
+            }
             _ => {
                 events.next();
-                continue;
             }
         }
     }
 }
 
+/// Consume the HTML comment at the current position and return its range, or return `None` if there is none.
+fn take_html_comment(events: &mut Peekable<OffsetIter<'_, '_>>) -> Option<Range<usize>> {
+    let (event, mut comment_range) =
+        events.next_if(|(e, _range)| matches!(e, Event::Html(text) if is_html_comment(text)))?;
+    // Multi-line comments are split into one event per line.
+    let mut is_closed = matches!(event, Event::Html(text) if text.contains("-->"));
+    while !is_closed {
+        match events.next_if(|(e, _range)| matches!(e, Event::Html(_))) {
+            Some((Event::Html(text), range)) => {
+                is_closed = text.contains("-->");
+                comment_range.end = range.end;
+            }
+            _ => break,
+        }
+    }
+    Some(comment_range)
+}
+
+fn is_html_comment(text: &str) -> bool {
+    text.trim_start().starts_with("<!--")
+}
+
 struct Headline {
     level: usize,
     version_prefix: String,
     version: Option<semver::Version>,
     date: Option<time::OffsetDateTime>,
+    date_precision: changelog::DatePrecision,
 }
 
 impl<'a> TryFrom<&'a str> for Headline {
@@ -273,6 +343,81 @@ fn headline<'a, E: ParseError<&'a str> + FromExternalError<&'a str, ()>>(
             u32::from_str(num).map_err(|_| ())
         })
     };
+    let date = map_res(
+        tuple((
+            take_n_digits(4),
+            tag("-"),
+            take_n_digits(2),
+            tag("-"),
+            take_n_digits(2),
+        )),
+        |(year, _, month, _, day)| {
+            time::Month::try_from(month as u8)
+                .map_err(|_| ())
+                .and_then(|month| {
+                    time::Date::from_calendar_date(year as i32, month, day as u8).map_err(|_| ())
+                })
+        },
+    );
+    // Variations of RFC3339 are written back in the canonical form, which keeps fractional seconds without trailing zeroes.
+    let time_of_day = map_res(
+        tuple((
+            take_n_digits(2),
+            tag(":"),
+            take_n_digits(2),
+            tag(":"),
+            take_n_digits(2),
+            opt(preceded(tag("."), take_while1(|c: char| c.is_ascii_digit()))),
+        )),
+        |(hour, _, minute, _, second, fraction)| {
+            let nanosecond = fraction.map_or(0, |fraction| {
+                fraction
+                    .chars()
+                    .chain(std::iter::repeat('0'))
+                    .take(9)
+                    .fold(0, |nanos, digit| {
+                        nanos * 10 + digit.to_digit(10).expect("ascii digit")
+                    })
+            });
+            time::Time::from_hms_nano(hour as u8, minute as u8, second as u8, nanosecond)
+                .map_err(|_| ())
+        },
+    );
+    let offset = alt((
+        map(tag_no_case("Z"), |_| time::UtcOffset::UTC),
+        map_res(
+            tuple((
+                alt((tag("+"), tag("-"))),
+                take_n_digits(2),
+                tag(":"),
+                take_n_digits(2),
+            )),
+            |(sign, hours, _, minutes)| {
+                let sign = if sign == "-" { -1 } else { 1 };
+                time::UtcOffset::from_hms(sign * hours as i8, sign * minutes as i8, 0)
+                    .map_err(|_| ())
+            },
+        ),
+    ));
+    let date_time = map(
+        tuple((
+            date,
+            opt(preceded(
+                alt((tag_no_case("T"), tag(" "))),
+                tuple((time_of_day, offset)),
+            )),
+        )),
+        |(date, time_and_offset)| match time_and_offset {
+            Some((time, offset)) => (
+                time::PrimitiveDateTime::new(date, time).assume_offset(offset),
+                changelog::DatePrecision::Time,
+            ),
+            None => (
+                date.midnight().assume_utc(),
+                changelog::DatePrecision::Day,
+            ),
+        },
+    );
     map(
         terminated(
             tuple((
@@ -291,32 +436,7 @@ fn headline<'a, E: ParseError<&'a str> + FromExternalError<&'a str, ()>>(
                 ),
                 opt(preceded(
                     greedy_whitespace,
-                    delimited(
-                        tag("("),
-                        map_res(
-                            tuple((
-                                take_n_digits(4),
-                                tag("-"),
-                                take_n_digits(2),
-                                tag("-"),
-                                take_n_digits(2),
-                            )),
-                            |(year, _, month, _, day)| {
-                                time::Month::try_from(month as u8).map_err(|_| ()).and_then(
-                                    |month| {
-                                        time::Date::from_calendar_date(
-                                            year as i32,
-                                            month,
-                                            day as u8,
-                                        )
-                                        .map_err(|_| ())
-                                        .map(|d| d.midnight().assume_utc())
-                                    },
-                                )
-                            },
-                        ),
-                        tag(")"),
-                    ),
+                    delimited(tag("("), date_time, tag(")")),
                 )),
             )),
             greedy_whitespace,
@@ -325,7 +445,8 @@ fn headline<'a, E: ParseError<&'a str> + FromExternalError<&'a str, ()>>(
             level: hashes.len(),
             version_prefix: prefix.map(ToOwned::to_owned).unwrap_or_else(String::new),
             version,
-            date,
+            date: date.map(|(date, _)| date),
+            date_precision: date.map(|(_, precision)| precision).unwrap_or_default(),
         },
     )(i)
 }
@@ -32,6 +32,13 @@ impl Section {
         let mut segments = Vec::new();
         let history = &segment.history;
         if !history.is_empty() {
+            if let Some(breaking_changes) = section::segment::BreakingChanges::from_history(history)
+                .filter(|_| selection.contains(Selection::BREAKING_CHANGES))
+            {
+                segments.push(Segment::BreakingChanges(section::Data::Generated(
+                    breaking_changes,
+                )))
+            }
             let message_by_category = selection
                 .intersects(Selection::COMMIT_STATISTICS | Selection::COMMIT_DETAILS)
                 .then(|| {
@@ -126,6 +133,7 @@ impl Section {
         Section::Release {
             name: version,
             date,
+            date_precision: changelog::DatePrecision::Day,
             heading_level: changelog::DEFAULT_HEADING_LEVEL,
             version_prefix: Self::DEFAULT_PREFIX.to_owned(),
             segments,
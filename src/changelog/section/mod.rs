@@ -7,6 +7,7 @@ pub enum Segment {
     User {
         markdown: String,
     },
+    BreakingChanges(Data<segment::BreakingChanges>),
     Details(Data<segment::Details>),
     Statistics(Data<segment::CommitStatistics>),
     Clippy(Data<segment::ThanksClippy>),
@@ -31,7 +32,10 @@ impl Segment {
     pub fn is_read_only(&self) -> bool {
         match self {
             Segment::User { .. } => false,
-            Segment::Clippy(_) | Segment::Statistics(_) | Segment::Details(_) => true,
+            Segment::Clippy(_)
+            | Segment::Statistics(_)
+            | Segment::Details(_)
+            | Segment::BreakingChanges(_) => true,
         }
     }
 }
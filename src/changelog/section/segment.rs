@@ -38,6 +38,48 @@ pub mod details {
     }
 }
 
+pub mod breaking_changes {
+    use git_repository as git;
+
+    #[derive(PartialEq, Eq, Debug, Clone)]
+    pub struct Message {
+        pub title: String,
+        /// The description of the breaking change from the `BREAKING CHANGE:` footer, if present.
+        pub description: Option<String>,
+        pub id: git::ObjectId,
+    }
+
+    impl From<&crate::commit::history::Item> for Message {
+        fn from(v: &crate::commit::history::Item) -> Self {
+            Message {
+                title: v.message.title.to_owned(),
+                description: v.message.breaking_description.clone(),
+                id: v.id,
+            }
+        }
+    }
+}
+
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct BreakingChanges {
+    /// All commits marked as breaking with `type!:` or a `BREAKING CHANGE:` footer, in the order of the history.
+    pub messages: Vec<breaking_changes::Message>,
+}
+
+impl BreakingChanges {
+    pub const TITLE: &'static str = "Breaking Changes";
+
+    /// Collect all breaking commits of `history`, or return `None` if there are none so the segment is omitted.
+    pub(crate) fn from_history(history: &[&crate::commit::history::Item]) -> Option<Self> {
+        let messages: Vec<_> = history
+            .iter()
+            .filter(|item| item.message.breaking)
+            .map(|&item| item.into())
+            .collect();
+        (!messages.is_empty()).then(|| BreakingChanges { messages })
+    }
+}
+
 #[derive(PartialEq, Eq, Debug, Clone)]
 pub struct Details {
     pub commits_by_category: BTreeMap<details::Category, Vec<details::Message>>,
@@ -79,5 +121,6 @@ bitflags! {
         const CLIPPY = 1<<0;
         const COMMIT_DETAILS = 1<<1;
         const COMMIT_STATISTICS = 1<<2;
+        const BREAKING_CHANGES = 1<<3;
     }
 }
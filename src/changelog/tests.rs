@@ -21,3 +21,455 @@ mod repository_url {
         }
     }
 }
+
+mod html_comments {
+    use crate::{
+        changelog::{section, write, Section},
+        ChangeLog,
+    };
+
+    #[test]
+    fn are_kept_verbatim_between_user_paragraphs() {
+        let input = "## v1.0.0 (2021-01-01)\n\nfirst paragraph\n\n<!-- release-date-override: 2021-01-02 -->\n\nsecond paragraph\n\n";
+        let log = ChangeLog::from_markdown(input);
+        let mut output = String::new();
+        log.write_to(&mut output, &write::Linkables::AsText, write::Components::all())
+            .unwrap();
+        assert_eq!(output, input);
+    }
+
+    #[test]
+    fn are_kept_in_place_after_generated_segments() {
+        let log = ChangeLog::from_markdown(
+            "## v1.0.0 (2021-01-01)\n\n### Commit Statistics\n\n - 1 commit contributed to the release.\n\n<!-- no-release -->\n\n<!--\nrelease-date-override: 2021-01-02\n-->\n\n### Commit Details\n\n * a commit\n",
+        );
+        match &log.sections[0] {
+            Section::Release { segments, .. } => assert_eq!(
+                segments,
+                &vec![
+                    section::Segment::Statistics(section::Data::Parsed),
+                    section::Segment::User {
+                        markdown: "<!-- no-release -->\n".into()
+                    },
+                    section::Segment::User {
+                        markdown: "<!--\nrelease-date-override: 2021-01-02\n-->\n".into()
+                    },
+                    section::Segment::Details(section::Data::Parsed),
+                ]
+            ),
+            Section::Verbatim { .. } => unreachable!("there is a release section"),
+        }
+    }
+
+    #[test]
+    fn multi_line_comments_after_generated_segments_round_trip() {
+        let comment = "<!--\nrelease-date-override: 2021-01-02\n-->\n\n";
+        let log = ChangeLog::from_markdown(&format!(
+            "## v1.0.0 (2021-01-01)\n\n### Commit Statistics\n\n - 1 commit contributed to the release.\n\n{}",
+            comment
+        ));
+        let mut output = String::new();
+        log.write_to(&mut output, &write::Linkables::AsText, write::Components::all())
+            .unwrap();
+        assert_eq!(
+            output,
+            format!("## v1.0.0 (2021-01-01)\n\n{}", comment),
+            "the comment is closed and the generated statistics are omitted as they were never generated"
+        );
+    }
+
+    #[test]
+    fn inline_comments_in_generated_list_items_are_not_moved() {
+        let log = ChangeLog::from_markdown(
+            "## v1.0.0 (2021-01-01)\n\n### Commit Details\n\n - foo <!-- c --> bar\n\n### Other\n\nuser\n",
+        );
+        match &log.sections[0] {
+            Section::Release { segments, .. } => {
+                assert_eq!(segments.len(), 2, "{:?}", segments);
+                assert!(matches!(
+                    segments[0],
+                    section::Segment::Details(section::Data::Parsed)
+                ));
+                assert!(matches!(
+                    &segments[1],
+                    section::Segment::User { markdown } if markdown.starts_with("### Other") && !markdown.contains("<!--")
+                ));
+            }
+            Section::Verbatim { .. } => unreachable!("there is a release section"),
+        }
+    }
+}
+
+mod headline_date {
+    use crate::{
+        changelog::{write, DatePrecision, Section},
+        ChangeLog,
+    };
+
+    fn date_time(hour: u8, minute: u8, offset_hours: i8) -> time::OffsetDateTime {
+        time::Date::from_calendar_date(2021, time::Month::August, 31)
+            .unwrap()
+            .with_hms(hour, minute, 0)
+            .unwrap()
+            .assume_offset(time::UtcOffset::from_hms(offset_hours, 0, 0).unwrap())
+    }
+
+    fn round_trip(input: &str) -> (Option<time::OffsetDateTime>, DatePrecision, String) {
+        let log = ChangeLog::from_markdown(input);
+        let mut output = String::new();
+        log.write_to(&mut output, &write::Linkables::AsText, write::Components::all())
+            .unwrap();
+        match &log.sections[0] {
+            Section::Release {
+                date, date_precision, ..
+            } => (*date, *date_precision, output),
+            Section::Verbatim { .. } => unreachable!("there is a release section"),
+        }
+    }
+
+    #[test]
+    fn day_only_is_utc_midnight_and_stays_day_only() {
+        let input = "## v1.0.0 (2021-08-31)\n\nhello\n\n";
+        let (date, precision, output) = round_trip(input);
+        assert_eq!(date, Some(date_time(0, 0, 0)));
+        assert_eq!(precision, DatePrecision::Day);
+        assert_eq!(output, input);
+    }
+
+    #[test]
+    fn timestamp_with_offset_keeps_time_and_offset() {
+        let input = "## v1.0.0 (2021-08-31T14:05:00+02:00)\n\nhello\n\n";
+        let (date, precision, output) = round_trip(input);
+        assert_eq!(date, Some(date_time(14, 5, 2)));
+        assert_eq!(precision, DatePrecision::Time);
+        assert_eq!(output, input);
+    }
+
+    #[test]
+    fn timestamp_in_utc() {
+        let input = "## v1.0.0 (2021-08-31T14:05:00Z)\n\nhello\n\n";
+        let (date, precision, output) = round_trip(input);
+        assert_eq!(date, Some(date_time(14, 5, 0)));
+        assert_eq!(precision, DatePrecision::Time);
+        assert_eq!(output, input);
+    }
+
+    #[test]
+    fn rfc3339_variations_are_parsed_and_written_canonically() {
+        for (input, expected_date, expected_output) in [
+            (
+                "## v1.0.0 (2021-08-31t14:05:00z)\n\n",
+                date_time(14, 5, 0),
+                "## v1.0.0 (2021-08-31T14:05:00Z)\n\n",
+            ),
+            (
+                "## v1.0.0 (2021-08-31 14:05:00+02:00)\n\n",
+                date_time(14, 5, 2),
+                "## v1.0.0 (2021-08-31T14:05:00+02:00)\n\n",
+            ),
+            (
+                "## v1.0.0 (2021-08-31T14:05:00+00:00)\n\n",
+                date_time(14, 5, 0),
+                "## v1.0.0 (2021-08-31T14:05:00Z)\n\n",
+            ),
+            (
+                "## v1.0.0 (2021-08-31T14:05:00-00:00)\n\n",
+                date_time(14, 5, 0),
+                "## v1.0.0 (2021-08-31T14:05:00Z)\n\n",
+            ),
+            (
+                "## v1.0.0 (2021-08-31T14:05:00.25+02:00)\n\n",
+                date_time(14, 5, 2) + time::Duration::milliseconds(250),
+                "## v1.0.0 (2021-08-31T14:05:00.25+02:00)\n\n",
+            ),
+            (
+                "## v1.0.0 (2021-08-31T14:05:00.000+02:00)\n\n",
+                date_time(14, 5, 2),
+                "## v1.0.0 (2021-08-31T14:05:00+02:00)\n\n",
+            ),
+        ] {
+            let (date, precision, output) = round_trip(input);
+            assert_eq!(date, Some(expected_date), "{:?}", input);
+            assert_eq!(precision, DatePrecision::Time, "{:?}", input);
+            assert_eq!(output, expected_output);
+        }
+    }
+
+    #[test]
+    fn merged_dates_follow_the_tag_and_keep_the_time_of_day() {
+        let mut log =
+            ChangeLog::from_markdown("## v1.0.0 (2021-08-31T14:05:00+02:00)\n\nhello\n\n");
+        log.sections[0].merge(Section::Release {
+            name: crate::changelog::Version::Semantic("1.0.0".parse().unwrap()),
+            date: Some(date_time(16, 0, 2)),
+            date_precision: DatePrecision::Day,
+            heading_level: 2,
+            version_prefix: Section::DEFAULT_PREFIX.into(),
+            unknown: String::new(),
+            removed_messages: vec![],
+            segments: vec![],
+        });
+        let mut output = String::new();
+        log.write_to(&mut output, &write::Linkables::AsText, write::Components::all())
+            .unwrap();
+        assert_eq!(output, "## v1.0.0 (2021-08-31T16:00:00+02:00)\n\nhello\n\n");
+    }
+
+    #[test]
+    fn commit_time_with_day_precision_is_rendered_as_utc_day() {
+        use git_repository as git;
+
+        // 2021-08-31T23:30:00Z, which is already the next day for the committer
+        let time = git::actor::Time::new(1630452600, 2 * 3600);
+        let section = Section::Release {
+            name: crate::changelog::Version::Semantic("1.0.0".parse().unwrap()),
+            date: Some(crate::utils::time_to_offset_date_time(time)),
+            date_precision: DatePrecision::Day,
+            heading_level: 2,
+            version_prefix: Section::DEFAULT_PREFIX.into(),
+            unknown: String::new(),
+            removed_messages: vec![],
+            segments: vec![],
+        };
+        let mut output = String::new();
+        section
+            .write_to(&mut output, &write::Linkables::AsText, write::Components::all())
+            .unwrap();
+        assert_eq!(output, "## v1.0.0 (2021-08-31)\n\n");
+    }
+
+    #[test]
+    fn commit_time_is_rendered_in_its_local_offset() {
+        use git_repository as git;
+
+        for (offset_in_seconds, expected) in [
+            (2 * 3600, "(2021-08-31T16:00:00+02:00)"),
+            (-5 * 3600, "(2021-08-31T09:00:00-05:00)"),
+            (0, "(2021-08-31T14:00:00Z)"),
+        ] {
+            // 2021-08-31T14:00:00Z
+            let time = git::actor::Time::new(1630418400, offset_in_seconds);
+            let section = Section::Release {
+                name: crate::changelog::Version::Semantic("1.0.0".parse().unwrap()),
+                date: Some(crate::utils::time_to_offset_date_time(time)),
+                date_precision: DatePrecision::Time,
+                heading_level: 2,
+                version_prefix: Section::DEFAULT_PREFIX.into(),
+                unknown: String::new(),
+                removed_messages: vec![],
+                segments: vec![],
+            };
+            let mut output = String::new();
+            section
+                .write_to(&mut output, &write::Linkables::AsText, write::Components::all())
+                .unwrap();
+            assert_eq!(output, format!("## v1.0.0 {}\n\n", expected));
+        }
+    }
+}
+
+mod breaking_changes {
+    use git_repository as git;
+
+    use crate::{
+        changelog::{
+            section,
+            section::{segment, Segment},
+            write, DatePrecision, Section, Version,
+        },
+        commit, ChangeLog,
+    };
+
+    fn id(hex: &str) -> git::ObjectId {
+        git::ObjectId::from_hex(hex.as_bytes()).unwrap()
+    }
+
+    fn item(message: &str) -> commit::history::Item {
+        commit::history::Item {
+            id: id("1111111111111111111111111111111111111111"),
+            message: message.into(),
+            commit_time: git::actor::Time::new(0, 0),
+            tree_id: id("2222222222222222222222222222222222222222"),
+            parent_tree_id: None,
+        }
+    }
+
+    fn release(segments: Vec<Segment>) -> Section {
+        Section::Release {
+            name: Version::Semantic("1.0.0".parse().unwrap()),
+            date: None,
+            date_precision: DatePrecision::Day,
+            heading_level: 2,
+            version_prefix: Section::DEFAULT_PREFIX.into(),
+            unknown: String::new(),
+            removed_messages: vec![],
+            segments,
+        }
+    }
+
+    fn generated(messages: Vec<segment::breaking_changes::Message>) -> Segment {
+        Segment::BreakingChanges(section::Data::Generated(segment::BreakingChanges { messages }))
+    }
+
+    fn to_markdown(section: &Section) -> String {
+        let mut out = String::new();
+        section
+            .write_to(&mut out, &write::Linkables::AsText, write::Components::all())
+            .unwrap();
+        out
+    }
+
+    fn segments(section: &Section) -> &[Segment] {
+        match section {
+            Section::Release { segments, .. } => segments,
+            Section::Verbatim { .. } => unreachable!("only release sections are used here"),
+        }
+    }
+
+    #[test]
+    fn are_only_collected_from_breaking_commits() {
+        assert!(segment::BreakingChanges::from_history(&[]).is_none());
+        let fix = item("fix: not breaking");
+        assert!(
+            segment::BreakingChanges::from_history(&[&fix]).is_none(),
+            "the segment is omitted without breaking changes"
+        );
+
+        let breaking = item("feat!: remove foo\n\nBREAKING CHANGE: use bar instead");
+        assert_eq!(
+            segment::BreakingChanges::from_history(&[&fix, &breaking]),
+            Some(segment::BreakingChanges {
+                messages: vec![segment::breaking_changes::Message {
+                    title: "remove foo".into(),
+                    description: Some("use bar instead".into()),
+                    id: breaking.id,
+                }]
+            })
+        );
+    }
+
+    #[test]
+    fn write_title_with_id_and_description_and_parse_back_as_read_only() {
+        let section = release(vec![generated(vec![
+            segment::breaking_changes::Message {
+                title: "remove foo".into(),
+                description: Some("use bar\ninstead".into()),
+                id: id("1111111111111111111111111111111111111111"),
+            },
+            segment::breaking_changes::Message {
+                title: "rename baz".into(),
+                description: None,
+                id: id("3333333333333333333333333333333333333333"),
+            },
+        ])]);
+        let md = to_markdown(&section);
+        assert_eq!(
+            md,
+            "## v1.0.0\n\n### Breaking Changes\n\n<csr-read-only-do-not-edit/>\n\n - remove foo (1111111)\n   use bar\n   instead\n - rename baz (3333333)\n\n"
+        );
+
+        let log = ChangeLog::from_markdown(&md);
+        assert!(matches!(
+            segments(&log.sections[0]),
+            [Segment::BreakingChanges(section::Data::Parsed)]
+        ));
+    }
+
+    #[test]
+    fn are_not_written_if_empty() {
+        assert_eq!(to_markdown(&release(vec![generated(vec![])])), "## v1.0.0\n\n");
+    }
+
+    #[test]
+    fn parsed_segment_is_replaced_when_merging() {
+        let messages = vec![segment::breaking_changes::Message {
+            title: "rename baz".into(),
+            description: None,
+            id: id("3333333333333333333333333333333333333333"),
+        }];
+        let mut parsed = ChangeLog::from_markdown(
+            "## v1.0.0\n\nhello\n\n### Breaking Changes\n\n<csr-read-only-do-not-edit/>\n\n - remove foo (1111111)\n\n",
+        )
+        .sections
+        .remove(0);
+        parsed.merge(release(vec![generated(messages.clone())]));
+        match segments(&parsed) {
+            [Segment::User { markdown }, Segment::BreakingChanges(section::Data::Generated(breaking))] => {
+                assert_eq!(markdown, "hello\n");
+                assert_eq!(breaking.messages, messages);
+            }
+            other => panic!("unexpected segments: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn are_not_added_to_sections_with_other_read_only_segments_when_merging() {
+        let mut parsed = ChangeLog::from_markdown(
+            "## v1.0.0\n\nhello\n\n### Commit Statistics\n\n<csr-read-only-do-not-edit/>\n\n - 1 commit contributed to the release.\n\n### Commit Details\n\n<csr-read-only-do-not-edit/>\n\n * a commit\n",
+        )
+        .sections
+        .remove(0);
+        let expected = parsed.clone();
+        let messages = vec![segment::breaking_changes::Message {
+            title: "rename baz".into(),
+            description: None,
+            id: id("3333333333333333333333333333333333333333"),
+        }];
+        parsed.merge(release(vec![generated(messages)]));
+        assert_eq!(
+            segments(&parsed),
+            segments(&expected),
+            "older releases and those whose breaking changes were deleted stay as they are"
+        );
+    }
+
+    #[test]
+    fn comments_before_the_read_only_marker_keep_the_segment_generated() {
+        let log = ChangeLog::from_markdown(
+            "## v1.0.0\n\n### Breaking Changes\n\n<!-- note -->\n\n<csr-read-only-do-not-edit/>\n\n - remove foo (1111111)\n\n### Other\n\nuser\n",
+        );
+        match segments(&log.sections[0]) {
+            [Segment::BreakingChanges(section::Data::Parsed), Segment::User { markdown: comment }, Segment::User { markdown: other }] =>
+            {
+                assert_eq!(comment, "<!-- note -->\n");
+                assert!(other.starts_with("### Other"), "{:?}", other);
+            }
+            other => panic!("unexpected segments: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn comments_after_a_hand_written_heading_stay_user_content() {
+        let input = "## v1.0.0 (2021-01-01)\n\n### Breaking Changes\n\n<!-- note -->\n\n - removed `foo()`\n\n";
+        let log = ChangeLog::from_markdown(input);
+        assert!(matches!(
+            segments(&log.sections[0]),
+            [Segment::User { markdown }] if markdown.starts_with("### Breaking Changes\n")
+        ));
+        assert_eq!(to_markdown(&log.sections[0]), input);
+    }
+
+    #[test]
+    fn hand_written_heading_is_kept_as_user_content() {
+        let input = "## v1.0.0 (2021-01-01)\n\n### Breaking Changes\n\n - removed `foo()`, use `bar()` instead\n\n";
+        let log = ChangeLog::from_markdown(input);
+        assert!(matches!(
+            segments(&log.sections[0]),
+            [Segment::User { markdown }] if markdown.starts_with("### Breaking Changes\n")
+        ));
+        assert_eq!(to_markdown(&log.sections[0]), input);
+    }
+
+    #[test]
+    fn hand_written_heading_prevents_generating_them_when_merging() {
+        let input = "## v1.0.0\n\nhello\n\n### Breaking Changes\n\n - removed `foo()`, use `bar()` instead\n\n";
+        let mut section = ChangeLog::from_markdown(input).sections.remove(0);
+        let messages = vec![segment::breaking_changes::Message {
+            title: "remove foo".into(),
+            description: None,
+            id: id("1111111111111111111111111111111111111111"),
+        }];
+        section.merge(release(vec![generated(messages)]));
+        assert_eq!(to_markdown(&section), input);
+    }
+}
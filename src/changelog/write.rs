@@ -114,6 +114,7 @@ impl Section {
             Section::Release {
                 name,
                 date,
+                date_precision,
                 heading_level,
                 version_prefix,
                 segments,
@@ -132,13 +133,30 @@ impl Section {
                     )?;
                     match date {
                         None => out.write_str("\n\n"),
-                        Some(date) => writeln!(
-                            out,
-                            " ({:04}-{:02}-{:02})\n",
-                            date.year(),
-                            date.month() as u32,
-                            date.day()
-                        ),
+                        Some(date) => match date_precision {
+                            changelog::DatePrecision::Day => {
+                                let date = date.to_offset(time::UtcOffset::UTC);
+                                writeln!(
+                                    out,
+                                    " ({:04}-{:02}-{:02})\n",
+                                    date.year(),
+                                    date.month() as u32,
+                                    date.day()
+                                )
+                            }
+                            changelog::DatePrecision::Time => writeln!(
+                                out,
+                                " ({:04}-{:02}-{:02}T{:02}:{:02}:{:02}{}{})\n",
+                                date.year(),
+                                date.month() as u32,
+                                date.day(),
+                                date.hour(),
+                                date.minute(),
+                                date.second(),
+                                format_fraction(date.nanosecond()),
+                                format_offset(date.offset())
+                            ),
+                        },
                     }?;
                 }
                 if !removed_messages.is_empty() && components.contains(Components::HTML_TAGS) {
@@ -169,6 +187,28 @@ fn assure_ends_with_empty_line(out: &mut impl std::fmt::Write, text: &str) -> st
     };
     Ok(())
 }
+fn format_fraction(nanosecond: u32) -> String {
+    if nanosecond == 0 {
+        String::new()
+    } else {
+        format!(".{:09}", nanosecond).trim_end_matches('0').into()
+    }
+}
+
+fn format_offset(offset: time::UtcOffset) -> String {
+    if offset.is_utc() {
+        "Z".into()
+    } else {
+        let (hours, minutes, _) = offset.as_hms();
+        format!(
+            "{}{:02}:{:02}",
+            if offset.is_negative() { '-' } else { '+' },
+            hours.abs(),
+            minutes.abs()
+        )
+    }
+}
+
 fn heading(level: usize) -> String {
     "#".repeat(level)
 }
@@ -330,6 +370,34 @@ impl section::Segment {
                     if *count > 1 { "times" } else { "time" }
                 )?;
             }
+            Segment::BreakingChanges(section::Data::Generated(segment::BreakingChanges {
+                messages,
+            })) if !messages.is_empty() => {
+                writeln!(
+                    out,
+                    "{} {}\n",
+                    heading(section_level),
+                    segment::BreakingChanges::TITLE
+                )?;
+                if write_html {
+                    writeln!(out, "{}", Section::READONLY_TAG)?;
+                }
+                for message in messages {
+                    writeln!(
+                        out,
+                        " - {} ({})",
+                        message.title,
+                        format_oid(&message.id, link_mode)
+                    )?;
+                    if let Some(description) = &message.description {
+                        for line in description.lines() {
+                            writeln!(out, "   {}", line)?;
+                        }
+                    }
+                }
+                writeln!(out)?;
+            }
+            Segment::BreakingChanges(_) => {}
             Segment::Clippy(_) => {}
             Segment::Statistics(_) => {}
             Segment::Details(_) => {}
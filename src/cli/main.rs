@@ -40,6 +40,7 @@ fn main() -> anyhow::Result<()> {
             allow_dirty,
             ignore_instability,
             no_publish,
+            max_concurrent_publishes,
             no_tag,
             no_push,
             changelog_without,
@@ -53,6 +54,7 @@ fn main() -> anyhow::Result<()> {
             no_changelog_links,
             no_changelog_preview,
             no_changelog_github_release,
+            changelog_local_timestamps,
             allow_fully_generated_changelogs,
             no_dependencies,
             no_isolate_dependencies_from_breaking_changes,
@@ -84,6 +86,8 @@ fn main() -> anyhow::Result<()> {
                     allow_fully_generated_changelogs,
                     changelog_links: !no_changelog_links,
                     allow_changelog_github_release: !no_changelog_github_release,
+                    max_concurrent_publishes,
+                    changelog_local_timestamps,
                 },
                 crates,
                 to_bump_spec(bump.as_deref().unwrap_or(DEFAULT_BUMP_SPEC))?,
@@ -133,6 +137,7 @@ fn names_to_segment_selection(
                 "clippy" => Selection::CLIPPY,
                 "commit-details" => Selection::COMMIT_DETAILS,
                 "commit-statistics" => Selection::COMMIT_STATISTICS,
+                "breaking-changes" => Selection::BREAKING_CHANGES,
                 other => anyhow::bail!("Invalid changelog segment selector: {:?}", other),
             };
         }
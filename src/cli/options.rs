@@ -95,7 +95,14 @@ pub enum SubCommands {
         #[clap(long, help_heading = Some("CHANGELOG"))]
         no_changelog_links: bool,
 
-        /// Omits these kinds of generated changelog content, values are 'clippy', 'commit-statistics' and 'commit-details'
+        /// Write the date of new releases into changelogs with the time of day and the local UTC offset,
+        /// like '(2021-08-31T14:05:00+02:00)', instead of the day only.
+        ///
+        /// Such dates keep their time of day when changelogs are regenerated.
+        #[clap(long, help_heading = Some("CHANGELOG"))]
+        changelog_local_timestamps: bool,
+
+        /// Omits these kinds of generated changelog content, values are 'clippy', 'commit-statistics', 'commit-details' and 'breaking-changes'
         #[clap(long, help_heading = Some("CHANGELOG"))]
         changelog_without: Vec<String>,
 
@@ -130,6 +137,15 @@ pub enum SubCommands {
         #[clap(long, help_heading = Some("CUSTOMIZATION"))]
         no_publish: bool,
 
+        /// The maximum amount of crates to publish in parallel.
+        ///
+        /// Crates are published in layers so that those not depending on each other can be published concurrently,
+        /// waiting for all crates of a layer to arrive in the crates index before publishing the next one.
+        /// With more than one concurrent publish, each uses its own target directory below the workspace target directory
+        /// to allow verification builds to run in parallel, at the cost of not sharing build artifacts.
+        #[clap(long, default_value = "1", help_heading = Some("CUSTOMIZATION"))]
+        max_concurrent_publishes: usize,
+
         /// Don't create tags indicating the version numbers of all crates that are to be published after changing
         /// their manifests.
         #[clap(long, help_heading = Some("CUSTOMIZATION"))]
@@ -171,7 +187,7 @@ pub enum SubCommands {
         #[clap(long, short = 'e', help_heading = Some("MAJOR"))]
         execute: bool,
 
-        /// omits these kinds of generated changelog content, values are 'clippy', 'commit-statistics' and 'commit-details'
+        /// omits these kinds of generated changelog content, values are 'clippy', 'commit-statistics', 'commit-details' and 'breaking-changes'
         #[clap(long, help_heading = Some("CUSTOMIZATION"))]
         without: Vec<String>,
 
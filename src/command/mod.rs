@@ -25,6 +25,10 @@ pub mod release {
         pub allow_fully_generated_changelogs: bool,
         pub changelog_links: bool,
         pub allow_changelog_github_release: bool,
+        /// Stamp new release sections with the time of day and local UTC offset instead of just the day.
+        pub changelog_local_timestamps: bool,
+        /// The amount of crates without dependency relationship to each other that may be published at the same time.
+        pub max_concurrent_publishes: usize,
     }
 }
 #[path = "release/mod.rs"]
@@ -1,14 +1,80 @@
-use std::process::Command;
+use std::{
+    process::Command,
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        Mutex,
+    },
+};
 
 use anyhow::bail;
-use cargo_metadata::Package;
+use cargo_metadata::{camino::Utf8Path, Package};
 
 use super::Options;
 use crate::utils::will;
 
+/// Publish all `publishees` of a single layer, i.e. crates that don't depend on each other, by calling `publish` on up to
+/// `max_concurrent_publishes` threads at once. `publish` receives the index of the worker if more than one is used,
+/// which allows each of them to use their own target directory.
+///
+/// Returns all crates that were published successfully, along with the first error if one occurred.
+/// After a failure no new publishes are started, but those already running are allowed to finish.
+pub(in crate::command::release_impl) fn publish_crates<'meta>(
+    publishees: &[&'meta Package],
+    max_concurrent_publishes: usize,
+    publish: impl Fn(&'meta Package, Option<usize>) -> anyhow::Result<()> + Sync,
+) -> (Vec<&'meta Package>, Option<anyhow::Error>) {
+    let failed_to_publish =
+        |err: anyhow::Error, publishee: &Package| err.context(format!("Failed to publish '{}'", publishee.name));
+    let num_workers = max_concurrent_publishes.max(1).min(publishees.len());
+    if num_workers <= 1 {
+        let mut succeeded = Vec::new();
+        for publishee in publishees {
+            if let Err(err) = publish(publishee, None) {
+                return (succeeded, Some(failed_to_publish(err, publishee)));
+            }
+            succeeded.push(*publishee);
+        }
+        return (succeeded, None);
+    }
+
+    let cancelled = AtomicBool::new(false);
+    let next_publishee = AtomicUsize::new(0);
+    let succeeded = Mutex::new(Vec::new());
+    let first_error = Mutex::new(None);
+    std::thread::scope(|scope| {
+        for worker in 0..num_workers {
+            let (next_publishee, cancelled, succeeded, first_error, publish) =
+                (&next_publishee, &cancelled, &succeeded, &first_error, &publish);
+            scope.spawn(move || {
+                while !cancelled.load(Ordering::SeqCst) {
+                    let publishee = match publishees.get(next_publishee.fetch_add(1, Ordering::SeqCst)) {
+                        Some(p) => *p,
+                        None => break,
+                    };
+                    match publish(publishee, Some(worker)) {
+                        Ok(()) => succeeded.lock().expect("not poisoned").push(publishee),
+                        Err(err) => {
+                            cancelled.store(true, Ordering::SeqCst);
+                            first_error
+                                .lock()
+                                .expect("not poisoned")
+                                .get_or_insert(failed_to_publish(err, publishee));
+                        }
+                    }
+                }
+            });
+        }
+    });
+
+    let mut succeeded = succeeded.into_inner().expect("not poisoned");
+    succeeded.sort_by_key(|p| publishees.iter().position(|c| c.id == p.id));
+    (succeeded, first_error.into_inner().expect("not poisoned"))
+}
+
 pub(in crate::command::release_impl) fn publish_crate(
     publishee: &Package,
     prevent_default_members: bool,
+    target_dir: Option<&Utf8Path>,
     Options {
         skip_publish,
         dry_run,
@@ -39,6 +105,9 @@ pub(in crate::command::release_impl) fn publish_crate(
             c.arg("--dry-run");
         }
         c.arg("--manifest-path").arg(&publishee.manifest_path);
+        if let Some(target_dir) = target_dir {
+            c.arg("--target-dir").arg(target_dir);
+        }
         if prevent_default_members {
             c.arg("--package").arg(&publishee.name);
         }
@@ -51,8 +120,9 @@ pub(in crate::command::release_impl) fn publish_crate(
             bail!("Could not successfully execute 'cargo publish'.")
         } else {
             log::warn!(
-                "'cargo publish' run {} failed but we retry up to {} times to rule out flakiness",
+                "'cargo publish' run {} of '{}' failed but we retry up to {} times to rule out flakiness",
                 attempt,
+                publishee.name,
                 max_attempts
             );
         }
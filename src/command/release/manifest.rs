@@ -417,6 +417,7 @@ fn gather_changelog_data<'a, 'meta>(
     Options {
         dry_run,
         generator_segments,
+        changelog_local_timestamps,
         ..
     }: Options,
 ) -> anyhow::Result<GatherOutcome<'meta>> {
@@ -430,6 +431,11 @@ fn gather_changelog_data<'a, 'meta>(
         made_change,
     } = &mut out;
     let next_commit_date = crate::utils::time_to_offset_date_time(crate::git::author()?.time);
+    let next_commit_date_precision = if changelog_local_timestamps {
+        changelog::DatePrecision::Time
+    } else {
+        changelog::DatePrecision::Day
+    };
     for (publishee, new_version) in crates_and_versions_to_be_published {
         let lock = git_repository::lock::File::acquire_to_update_resource(
             &publishee.manifest_path,
@@ -470,6 +476,7 @@ fn gather_changelog_data<'a, 'meta>(
                 changelog::Section::Release {
                     name: name @ changelog::Version::Unreleased,
                     date,
+                    date_precision,
                     ..
                 } => {
                     if !log_init_state.is_modified() {
@@ -482,6 +489,7 @@ fn gather_changelog_data<'a, 'meta>(
                     }
                     *name = changelog::Version::Semantic((*new_version).to_owned());
                     *date = Some(next_commit_date);
+                    *date_precision = next_commit_date_precision;
                     let recent_section = log.sections.remove(recent_idx);
                     match log
                         .sections
@@ -497,6 +505,7 @@ fn gather_changelog_data<'a, 'meta>(
                 changelog::Section::Release {
                     name: changelog::Version::Semantic(recent_version),
                     date,
+                    date_precision,
                     ..
                 } => {
                     if recent_version != *new_version {
@@ -508,6 +517,7 @@ fn gather_changelog_data<'a, 'meta>(
                         );
                     }
                     *date = Some(next_commit_date);
+                    *date_precision = next_commit_date_precision;
                 }
                 changelog::Section::Verbatim { .. } => {
                     unreachable!("BUG: checked in prior function")
@@ -10,7 +10,7 @@ use crate::{
         dependency::{ManifestAdjustment, VersionAdjustment},
         Dependency,
     },
-    utils::{try_to_published_crate_and_new_version, will},
+    utils::{names_and_versions, try_to_published_crate_and_new_version, will},
     version,
     version::BumpSpec,
 };
@@ -19,6 +19,8 @@ mod cargo;
 mod git;
 mod github;
 mod manifest;
+#[cfg(test)]
+mod tests;
 
 pub(crate) struct Context {
     base: crate::Context,
@@ -447,26 +449,66 @@ fn perform_release(
         Vec::<(&cargo_metadata::Package, &semver::Version)>::new();
     let mut publish_err = None;
     let prevent_default_members = ctx.base.meta.workspace_members.len() > 1;
-    for (publishee, new_version) in crates
+    let publishees: Vec<_> = crates
         .iter()
         .filter_map(try_to_published_crate_and_new_version)
-    {
-        if let Some((crate_, version)) = successful_publishees_and_version.last() {
-            if let Err(err) = wait_for_release(*crate_, version, options) {
-                log::warn!(
-                    "Failed to wait for crates-index update - trying to publish '{} v{}' anyway: {}.",
-                    publishee.name,
-                    new_version,
-                    err
-                );
+        .collect();
+    let layers = publish_layers(&ctx.base.meta, &publishees);
+    let max_concurrent_publishes = options.max_concurrent_publishes.max(1);
+    let target_directory = &ctx.base.meta.target_directory;
+    for (layer_idx, layer) in layers.iter().enumerate() {
+        if layer_idx > 0 {
+            for (crate_, version) in &layers[layer_idx - 1] {
+                if let Err(err) = wait_for_release(crate_, version, options) {
+                    log::warn!(
+                        "Failed to wait for crates-index update of '{} v{}' - trying to publish the next {} crate(s) anyway: {}.",
+                        crate_.name,
+                        version,
+                        layer.len(),
+                        err
+                    );
+                }
             }
         }
 
-        if let Err(err) = cargo::publish_crate(publishee, prevent_default_members, options) {
-            publish_err = Some(err);
+        if !options.skip_publish && (options.verbose || options.dry_run) {
+            log::info!(
+                "{} publish layer {} of {} with up to {} concurrent publishes: {}",
+                will(options.dry_run),
+                layer_idx + 1,
+                layers.len(),
+                max_concurrent_publishes.min(layer.len()),
+                names_and_versions(layer)
+            );
+        }
+        let layer_packages: Vec<_> = layer.iter().map(|(p, _)| *p).collect();
+        let (succeeded, err) = cargo::publish_crates(
+            &layer_packages,
+            max_concurrent_publishes,
+            |publishee, worker| {
+                // Concurrent verification builds would otherwise wait for each other to release the lock on the target directory.
+                let target_dir =
+                    worker.map(|idx| target_directory.join(format!("publish-worker-{}", idx)));
+                cargo::publish_crate(publishee, prevent_default_members, target_dir.as_deref(), options)
+            },
+        );
+        successful_publishees_and_version.extend(
+            layer
+                .iter()
+                .filter(|(p, _)| succeeded.iter().any(|s| s.id == p.id))
+                .copied(),
+        );
+        if let Some(err) = err {
+            publish_err = Some(if successful_publishees_and_version.is_empty() {
+                err.context("No crate was published")
+            } else {
+                err.context(format!(
+                    "Successfully published crates: {}",
+                    names_and_versions(&successful_publishees_and_version)
+                ))
+            });
             break;
         }
-        successful_publishees_and_version.push((publishee, new_version));
         // if let Some(tag_name) = git::create_version_tag(
         // publishee,
         // new_version,
@@ -494,6 +536,36 @@ fn perform_release(
     publish_err.map(Err).unwrap_or(Ok(()))
 }
 
+/// Group `publishees`, which are expected in dependency order, into layers of crates that don't depend on each other.
+/// Each crate is placed in the layer right after the last layer containing one of its dependencies, so all crates in
+/// a layer can be published concurrently once all previous layers have arrived in the crates index.
+fn publish_layers<'meta, 'a>(
+    meta: &'meta cargo_metadata::Metadata,
+    publishees: &[(&'meta cargo_metadata::Package, &'a semver::Version)],
+) -> Vec<Vec<(&'meta cargo_metadata::Package, &'a semver::Version)>> {
+    let mut layer_by_name = BTreeMap::<&str, usize>::new();
+    let mut layers = Vec::<Vec<_>>::new();
+    for (publishee, version) in publishees.iter().copied() {
+        let layer = publishee
+            .dependencies
+            .iter()
+            .filter(|dep| {
+                crate::utils::workspace_package_by_dependency(meta, dep)
+                    .map_or(false, |p| crate::utils::package_eq_dependency_ignore_dev_without_version(p, dep))
+            })
+            .filter_map(|dep| layer_by_name.get(dep.name.as_str()))
+            .map(|layer| layer + 1)
+            .max()
+            .unwrap_or(0);
+        layer_by_name.insert(publishee.name.as_str(), layer);
+        if layer == layers.len() {
+            layers.push(Vec::new());
+        }
+        layers[layer].push((publishee, version));
+    }
+    layers
+}
+
 fn wait_for_release(
     crate_: &cargo_metadata::Package,
     crate_version: &semver::Version,
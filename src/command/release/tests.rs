@@ -0,0 +1,197 @@
+use cargo_metadata::{Metadata, Package};
+
+/// Build workspace metadata with all `packages` as members, given as `(name, [(dependency, version requirement, kind)])`.
+fn workspace(packages: &[(&str, &[(&str, &str, &str)])]) -> Metadata {
+    let id = |name: &str| format!("{} 0.1.0 (path+file:///ws/{})", name, name);
+    let packages_json = packages
+        .iter()
+        .map(|(name, dependencies)| {
+            let dependencies = dependencies
+                .iter()
+                .map(|(name, req, kind)| {
+                    format!(
+                        r#"{{"name":"{}","source":null,"req":"{}","kind":{},"rename":null,"optional":false,"uses_default_features":true,"features":[],"target":null,"registry":null,"path":"/ws/{}"}}"#,
+                        name,
+                        req,
+                        if kind.is_empty() {
+                            "null".to_string()
+                        } else {
+                            format!("\"{}\"", kind)
+                        },
+                        name
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join(",");
+            format!(
+                r#"{{"name":"{name}","version":"0.1.0","id":"{id}","license":null,"license_file":null,"description":null,"source":null,"dependencies":[{dependencies}],"targets":[],"features":{{}},"manifest_path":"/ws/{name}/Cargo.toml","metadata":null,"publish":null,"authors":[],"categories":[],"keywords":[],"readme":null,"repository":null,"homepage":null,"documentation":null,"edition":"2021","links":null,"default_run":null,"rust_version":null}}"#,
+                name = name,
+                id = id(name),
+                dependencies = dependencies
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(",");
+    let members = packages
+        .iter()
+        .map(|(name, _)| format!("\"{}\"", id(name)))
+        .collect::<Vec<_>>()
+        .join(",");
+    cargo_metadata::MetadataCommand::parse(format!(
+        r#"{{"packages":[{}],"workspace_members":[{}],"resolve":null,"target_directory":"/ws/target","version":1,"workspace_root":"/ws","metadata":null}}"#,
+        packages_json, members
+    ))
+    .expect("valid metadata")
+}
+
+fn package<'a>(meta: &'a Metadata, name: &str) -> &'a Package {
+    meta.packages
+        .iter()
+        .find(|p| p.name == name)
+        .expect("package exists")
+}
+
+mod publish_layers {
+    use super::{package, workspace};
+    use crate::command::release_impl::publish_layers;
+
+    fn layer_names(meta: &cargo_metadata::Metadata, publishees: &[&str]) -> Vec<Vec<String>> {
+        let publishees: Vec<_> = publishees
+            .iter()
+            .map(|name| {
+                let p = package(meta, name);
+                (p, &p.version)
+            })
+            .collect();
+        publish_layers(meta, &publishees)
+            .into_iter()
+            .map(|layer| layer.into_iter().map(|(p, _)| p.name.clone()).collect())
+            .collect()
+    }
+
+    #[test]
+    fn diamond_dependencies_publish_the_middle_concurrently() {
+        let meta = workspace(&[
+            ("a", &[]),
+            ("b", &[("a", "^0.1.0", "")]),
+            ("c", &[("a", "^0.1.0", "build")]),
+            ("d", &[("b", "^0.1.0", ""), ("c", "^0.1.0", "")]),
+        ]);
+        assert_eq!(
+            layer_names(&meta, &["a", "b", "c", "d"]),
+            vec![vec!["a"], vec!["b", "c"], vec!["d"]]
+        );
+    }
+
+    #[test]
+    fn dev_dependencies_only_count_with_a_specific_version() {
+        let meta = workspace(&[
+            ("a", &[]),
+            ("without-version", &[("a", "*", "dev")]),
+            ("with-version", &[("a", "=0.1.0", "dev")]),
+        ]);
+        assert_eq!(
+            layer_names(&meta, &["a", "without-version", "with-version"]),
+            vec![vec!["a", "without-version"], vec!["with-version"]]
+        );
+    }
+
+    #[test]
+    fn workspace_dependencies_which_are_not_published_are_ignored() {
+        let meta = workspace(&[
+            ("unchanged", &[]),
+            ("a", &[("unchanged", "^0.1.0", "")]),
+            ("b", &[("a", "^0.1.0", "")]),
+        ]);
+        assert_eq!(layer_names(&meta, &["a", "b"]), vec![vec!["a"], vec!["b"]]);
+    }
+}
+
+mod publish_crates {
+    use std::sync::{mpsc, Barrier, Mutex};
+
+    use super::{package, workspace};
+    use crate::command::release_impl::cargo::publish_crates;
+
+    #[test]
+    fn a_failure_prevents_further_publishes_with_a_single_worker() {
+        let meta = workspace(&[("a", &[]), ("b", &[]), ("c", &[])]);
+        let publishees: Vec<_> = ["a", "b", "c"].iter().map(|n| package(&meta, n)).collect();
+        let attempted = Mutex::new(Vec::new());
+        let (succeeded, err) = publish_crates(&publishees, 1, |p, worker| {
+            assert_eq!(worker, None, "a single worker uses the default target directory");
+            attempted.lock().unwrap().push(p.name.clone());
+            if p.name == "b" {
+                anyhow::bail!("cargo failed")
+            }
+            Ok(())
+        });
+        assert_eq!(succeeded.iter().map(|p| p.name.as_str()).collect::<Vec<_>>(), ["a"]);
+        assert_eq!(attempted.into_inner().unwrap(), ["a", "b"]);
+        assert_eq!(err.expect("failed").to_string(), "Failed to publish 'b'");
+    }
+
+    #[test]
+    fn a_failure_lets_running_publishes_finish_with_multiple_workers() {
+        let meta = workspace(&[("a", &[]), ("b", &[]), ("c", &[]), ("d", &[]), ("e", &[])]);
+        let publishees: Vec<_> = ["a", "b", "c", "d", "e"]
+            .iter()
+            .map(|n| package(&meta, n))
+            .collect();
+        let attempted = Mutex::new(Vec::new());
+        // Both workers have to be publishing 'a' and 'b' at the same time, and 'a' only finishes once 'b' failed.
+        let both_running = Barrier::new(2);
+        let (b_failed, wait_for_b) = mpsc::channel();
+        let wait_for_b = Mutex::new(wait_for_b);
+        let (succeeded, err) = publish_crates(&publishees, 2, |p, worker| {
+            attempted.lock().unwrap().push((p.name.clone(), worker));
+            match p.name.as_str() {
+                "a" => {
+                    both_running.wait();
+                    wait_for_b.lock().unwrap().recv().expect("b fails");
+                    Ok(())
+                }
+                "b" => {
+                    both_running.wait();
+                    b_failed.send(()).expect("a waits");
+                    anyhow::bail!("cargo failed")
+                }
+                _ => Ok(()),
+            }
+        });
+        assert_eq!(err.expect("failed").to_string(), "Failed to publish 'b'");
+
+        let attempted = attempted.into_inner().unwrap();
+        let worker_of = |name: &str| {
+            attempted
+                .iter()
+                .find(|(n, _)| n == name)
+                .map(|(_, worker)| *worker)
+                .expect("attempted")
+        };
+        let (worker_a, worker_b) = (worker_of("a"), worker_of("b"));
+        let mut workers = vec![worker_a, worker_b];
+        workers.sort();
+        assert_eq!(workers, [Some(0), Some(1)], "each worker is identified");
+        assert!(
+            attempted
+                .iter()
+                .skip_while(|(n, _)| n != "b")
+                .skip(1)
+                .all(|(_, worker)| *worker == worker_a),
+            "the worker that failed doesn't start another publish"
+        );
+        let mut published: Vec<_> = attempted
+            .iter()
+            .map(|(n, _)| n.as_str())
+            .filter(|n| *n != "b")
+            .collect();
+        published.sort_unstable();
+        assert_eq!(
+            succeeded.iter().map(|p| p.name.as_str()).collect::<Vec<_>>(),
+            published,
+            "running publishes finish and are reported in order"
+        );
+        assert_eq!(published[0], "a");
+    }
+}
@@ -91,17 +91,115 @@ mod additions {
     }
 }
 
+mod conventional {
+    const BREAKING_CHANGE_TOKENS: &[&str] = &["BREAKING CHANGE:", "BREAKING-CHANGE:"];
+    /// The known conventional types, which are stripped from titles.
+    pub const KINDS: &[&str] = &[
+        "feat", "fix", "docs", "style", "refactor", "perf", "test", "build", "ci", "chore", "revert",
+    ];
+
+    /// Split a title like `type(scope)!: description` into its type, description and whether it's marked as breaking,
+    /// or return `None` if it's not a conventional title with a lower-case type.
+    pub fn parse_type(title: &str) -> Option<(&str, &str, bool)> {
+        let (prefix, description) = title.split_once(':')?;
+        let (prefix, breaking) = match prefix.strip_suffix('!') {
+            Some(prefix) => (prefix, true),
+            None => (prefix, false),
+        };
+        let kind = match prefix.find('(') {
+            Some(pos) => prefix.ends_with(')').then(|| &prefix[..pos])?,
+            None => prefix,
+        };
+        let description = description.trim_start();
+        (!kind.is_empty() && !description.is_empty() && kind.chars().all(|c| c.is_ascii_lowercase()))
+            .then(|| (kind, description, breaking))
+    }
+
+    /// Remove the `BREAKING CHANGE: description` footer from `body` and return the remaining body along with the
+    /// description, which may continue on indented lines.
+    pub fn strip_breaking_footer(body: &str) -> (String, Option<String>) {
+        let mut description = None::<String>;
+        let mut remaining = Vec::new();
+        let mut lines = body.lines().peekable();
+        while let Some(line) = lines.next() {
+            match BREAKING_CHANGE_TOKENS
+                .iter()
+                .find_map(|token| line.strip_prefix(token))
+                .filter(|_| description.is_none())
+            {
+                Some(first_line) => {
+                    let mut text = first_line.trim().to_owned();
+                    while let Some(continuation) =
+                        lines.next_if(|l| l.starts_with(char::is_whitespace) && !l.trim().is_empty())
+                    {
+                        text.push('\n');
+                        text.push_str(continuation.trim());
+                    }
+                    description = Some(text);
+                }
+                None => remaining.push(line),
+            }
+        }
+        let mut remaining = remaining.join("\n");
+        remaining.truncate(remaining.trim_end().len());
+        (remaining, description.filter(|d| !d.is_empty()))
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn parse_type_with_scope_and_breaking_marker() {
+            assert_eq!(parse_type("feat(parse)!: hello"), Some(("feat", "hello", true)));
+            assert_eq!(parse_type("fix: hello"), Some(("fix", "hello", false)));
+            assert_eq!(parse_type("deps!: drop foo 1.x"), Some(("deps", "drop foo 1.x", true)));
+            assert_eq!(parse_type("not conventional: at all"), None);
+            assert_eq!(parse_type("hello"), None);
+        }
+
+        #[test]
+        fn parse_type_ignores_non_lowercase_types() {
+            assert_eq!(parse_type("README: fix typo"), None);
+            assert_eq!(parse_type("WIP!: try something"), None);
+            assert_eq!(parse_type("Feat: uppercase kinds are not conventional"), None);
+        }
+
+        #[test]
+        fn breaking_footer_with_continuation_lines() {
+            assert_eq!(
+                strip_breaking_footer("body\n\nBREAKING CHANGE: first\n  second"),
+                ("body".into(), Some("first\nsecond".into()))
+            );
+        }
+    }
+}
+
 impl From<&'_ str> for Message {
     fn from(m: &str) -> Self {
         let m = git::objs::commit::MessageRef::from_bytes(m.as_bytes());
-        let title = m.summary().as_ref().to_string().into();
-        let body = m.body().map(|b| b.without_trailer().to_str_lossy());
-        let breaking = false;
-        let breaking_description = None;
+        let summary = m.summary();
+        let summary = summary.to_str_lossy();
+        let (title, mut breaking) = match conventional::parse_type(&summary) {
+            Some((kind, description, breaking)) if conventional::KINDS.contains(&kind) => {
+                (description.to_owned().into(), breaking)
+            }
+            Some((_unknown_kind, _description, breaking)) => {
+                (summary.as_ref().to_owned().into(), breaking)
+            }
+            None => (summary.as_ref().to_owned().into(), false),
+        };
+        let breaking_description = m
+            .body
+            .and_then(|b| conventional::strip_breaking_footer(&b.to_str_lossy()).1);
+        breaking |= breaking_description.is_some();
+        let body = m
+            .body()
+            .map(|b| conventional::strip_breaking_footer(&b.without_trailer().to_str_lossy()).0);
         let (title, additions) = additions::strip(title);
         Message {
             title: title.into_owned(),
-            body: body.map(|b| b.into_owned()),
+            body,
             breaking,
             breaking_description,
             additions,
@@ -155,6 +253,42 @@ mod tests {
         )
     }
 
+    #[test]
+    fn non_conventional_prefix_is_kept_and_not_breaking() {
+        assert_eq!(
+            Message::from("README: fix typo"),
+            Message {
+                title: "README: fix typo".into(),
+                body: None,
+                breaking: false,
+                breaking_description: None,
+                additions: vec![]
+            }
+        )
+    }
+
+    #[test]
+    fn breaking_marker_on_unknown_type_keeps_title() {
+        let m = Message::from("change!: drop support for foo 1.x");
+        assert_eq!(m.title, "change!: drop support for foo 1.x");
+        assert!(m.breaking, "any lower-case type can be marked as breaking");
+    }
+
+    #[test]
+    fn conventional_without_breaking_marker() {
+        let m = Message::from("fix(parse): handle empty input");
+        assert_eq!(m.title, "handle empty input");
+        assert!(!m.breaking);
+    }
+
+    #[test]
+    fn breaking_change_footer_without_marker_in_title() {
+        let m = Message::from("refactor: rename things\n\nBREAKING CHANGE: `foo()` is now `bar()`");
+        assert_eq!(m.title, "rename things");
+        assert!(m.breaking, "the footer alone marks a change as breaking");
+        assert_eq!(m.breaking_description.as_deref(), Some("`foo()` is now `bar()`"));
+    }
+
     #[test]
     fn conventional_with_additions() {
         assert_eq!(
@@ -306,5 +306,5 @@ mod tests {
 pub fn time_to_offset_date_time(time: git::actor::Time) -> OffsetDateTime {
     time::OffsetDateTime::from_unix_timestamp(time.seconds_since_unix_epoch as i64)
         .expect("always valid unix time")
-        .replace_offset(time::UtcOffset::from_whole_seconds(time.offset_in_seconds).expect("valid offset"))
+        .to_offset(time::UtcOffset::from_whole_seconds(time.offset_in_seconds).expect("valid offset"))
 }